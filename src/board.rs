@@ -1,10 +1,16 @@
 use select::document::Document;
 use select::node::Node;
 use select::predicate::{Class, Name, Predicate};
+use serde::{Deserialize, Serialize};
 
+#[derive(Clone, Serialize, Deserialize)]
 pub(crate) struct Board {
     pub(crate) name: String,
     pub uri: String,
+    /// Overrides the default clien host for this board, so the scraper can
+    /// be pointed at a mirror without recompiling.
+    #[serde(default)]
+    pub base_host: Option<String>,
 }
 
 impl Board {
@@ -12,6 +18,7 @@ impl Board {
         Self {
             name: name.to_string(),
             uri: uri.to_string(),
+            base_host: None,
         }
     }
 }
@@ -100,4 +107,79 @@ impl BoardRow {
         }
         item_nickname.trim().to_string()
     }
+}
+
+#[derive(Clone)]
+pub(crate) struct Comment {
+    pub nickname: String,
+    pub text: String,
+    pub timestamp: String,
+}
+
+#[derive(Clone)]
+pub(crate) struct Post {
+    pub title: String,
+    pub author: String,
+    pub body: String,
+    pub comments: Vec<Comment>,
+}
+
+impl Post {
+    pub fn get_post_data(doc: String) -> Post {
+        let document = Document::from(doc.as_str());
+
+        let title = document
+            .select(Class("post_subject"))
+            .next()
+            .map(|node| node.text().trim().to_string())
+            .unwrap_or_default();
+
+        let author = document
+            .select(Class("post_author").descendant(Class("nickname")))
+            .next()
+            .map(|node| node.text().trim().to_string())
+            .unwrap_or_default();
+
+        let body = document
+            .select(Class("post_article"))
+            .next()
+            .map(|node| node.text().trim().to_string())
+            .unwrap_or_default();
+
+        let comments = document
+            .select(Class("comment_row"))
+            .map(Post::get_comment)
+            .collect();
+
+        Post {
+            title,
+            author,
+            body,
+            comments,
+        }
+    }
+
+    fn get_comment(comment_node: Node) -> Comment {
+        let nickname = comment_node
+            .select(Class("comment_nickname").descendant(Class("nickname")))
+            .next()
+            .map(|node| node.text().trim().to_string())
+            .unwrap_or_default();
+        let text = comment_node
+            .select(Class("comment_content"))
+            .next()
+            .map(|node| node.text().trim().to_string())
+            .unwrap_or_default();
+        let timestamp = comment_node
+            .select(Class("comment_time").descendant(Class("timestamp")))
+            .next()
+            .map(|node| node.text().trim().to_string())
+            .unwrap_or_default();
+
+        Comment {
+            nickname,
+            text,
+            timestamp,
+        }
+    }
 }
\ No newline at end of file
@@ -1,16 +1,24 @@
-use crossterm::event::{self, Event as CEvent, KeyCode};
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event as CEvent, KeyCode, MouseButton,
+    MouseEventKind,
+};
+use crossterm::execute;
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use reqwest::blocking::Response;
 use reqwest::Method;
-use std::sync::mpsc;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
 use std::time::{Duration, Instant};
-use std::{io, thread};
+use std::{env, fs, io, thread};
 use thiserror::Error;
 use tui::backend::CrosstermBackend;
-use tui::layout::{Alignment, Constraint, Direction, Layout};
+use tui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use tui::style::{Color, Modifier, Style};
 use tui::text::{Span, Spans};
 use tui::widgets::{
-    Block, BorderType, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Table, Tabs,
+    Block, BorderType, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Table, TableState,
+    Tabs, Wrap,
 };
 use tui::Terminal;
 
@@ -34,6 +42,7 @@ pub enum Error {
 enum MenuItem {
     Home,
     Boards,
+    Post,
 }
 
 impl From<MenuItem> for usize {
@@ -41,15 +50,359 @@ impl From<MenuItem> for usize {
         match input {
             MenuItem::Home => 0,
             MenuItem::Boards => 1,
+            MenuItem::Post => 1,
+        }
+    }
+}
+
+/// Holds everything the draw loop needs to render, so `terminal.draw` never
+/// has to reach out to the network itself.
+struct App {
+    active_menu_item: MenuItem,
+    board_list_state: ListState,
+    row_state: TableState,
+    boards: Vec<board::Board>,
+    page: usize,
+    row_cache: HashMap<String, Vec<board::BoardRow>>,
+    pending: HashMap<String, Receiver<reqwest::Result<Response>>>,
+    current_post: Option<board::Post>,
+    post_pending: Option<Receiver<reqwest::Result<Response>>>,
+    post_scroll: u16,
+    board_list_area: Rect,
+    row_table_area: Rect,
+    search_mode: bool,
+    search_query: String,
+}
+
+impl App {
+    fn new() -> Result<Self, Error> {
+        let boards = read_boards()?;
+        let mut board_list_state = ListState::default();
+        board_list_state.select(Some(0));
+        let mut row_state = TableState::default();
+        row_state.select(Some(0));
+
+        Ok(Self {
+            active_menu_item: MenuItem::Home,
+            board_list_state,
+            row_state,
+            boards,
+            page: 0,
+            row_cache: HashMap::new(),
+            pending: HashMap::new(),
+            current_post: None,
+            post_pending: None,
+            post_scroll: 0,
+            board_list_area: Rect::default(),
+            row_table_area: Rect::default(),
+            search_mode: false,
+            search_query: String::new(),
+        })
+    }
+
+    fn selected_board(&self) -> &board::Board {
+        let selected = self
+            .board_list_state
+            .selected()
+            .expect("there is always a selected board");
+        &self.boards[selected]
+    }
+
+    /// Cache/in-flight key for the selected board's current page.
+    fn current_cache_key(&self) -> String {
+        cache_key(&self.selected_board().uri, self.page)
+    }
+
+    /// Kicks off a fetch for the selected board's current page if it isn't
+    /// already cached or in flight.
+    fn ensure_board_rows_requested(&mut self) {
+        let key = self.current_cache_key();
+        if self.row_cache.contains_key(&key) || self.pending.contains_key(&key) {
+            return;
+        }
+        let board = self.selected_board().clone();
+        self.pending
+            .insert(key, request_board_rows(&board, self.page));
+    }
+
+    /// Drops the cached rows (and any request in flight) for the selected
+    /// board's current page, then immediately re-requests them.
+    fn refresh_selected_board(&mut self) {
+        let key = self.current_cache_key();
+        self.row_cache.remove(&key);
+        self.pending.remove(&key);
+        self.ensure_board_rows_requested();
+    }
+
+    /// Switches to a different board, resetting pagination and row selection.
+    fn select_board(&mut self, index: usize) {
+        self.board_list_state.select(Some(index));
+        self.page = 0;
+        self.row_state.select(Some(0));
+        self.ensure_board_rows_requested();
+    }
+
+    /// Moves to `page` for the selected board, resetting row selection.
+    fn go_to_page(&mut self, page: usize) {
+        self.page = page;
+        self.row_state.select(Some(0));
+        self.ensure_board_rows_requested();
+    }
+
+    /// Moves the row selection within the currently loaded page, scrolling
+    /// the table viewport once the selection passes it (handled by
+    /// `TableState` itself).
+    fn move_row_selection(&mut self, delta: isize) {
+        let len = self.visible_rows().len();
+        if len == 0 {
+            return;
+        }
+        let selected = self.row_state.selected().unwrap_or(0) as isize;
+        let next = (selected + delta).clamp(0, len as isize - 1);
+        self.row_state.select(Some(next as usize));
+    }
+
+    /// The selected board's rows for the current page, narrowed down to
+    /// those fuzzy-matching `search_query` (title or author) and sorted by
+    /// descending match score. Returns every row, unsorted, when the query
+    /// is empty.
+    fn visible_rows(&self) -> Vec<&board::BoardRow> {
+        let rows = match self.row_cache.get(&self.current_cache_key()) {
+            Some(rows) => rows,
+            None => return vec![],
+        };
+
+        if self.search_query.is_empty() {
+            return rows.iter().collect();
+        }
+
+        let mut scored: Vec<(i32, &board::BoardRow)> = rows
+            .iter()
+            .filter_map(|row| {
+                let score = fuzzy_score(&row.title, &self.search_query)
+                    .max(fuzzy_score(&row.nickname, &self.search_query));
+                score.map(|score| (score, row))
+            })
+            .collect();
+        scored.sort_by(|(a, _), (b, _)| b.cmp(a));
+        scored.into_iter().map(|(_, row)| row).collect()
+    }
+
+    /// Enters search-input mode for the board rows.
+    fn start_search(&mut self) {
+        self.search_mode = true;
+    }
+
+    /// Appends a character to the search query and snaps the selection back
+    /// to the top of the filtered results.
+    fn push_search_char(&mut self, c: char) {
+        self.search_query.push(c);
+        self.row_state.select(Some(0));
+    }
+
+    /// Removes the last character from the search query.
+    fn pop_search_char(&mut self) {
+        self.search_query.pop();
+        self.row_state.select(Some(0));
+    }
+
+    /// Clears the query and leaves search-input mode, restoring the full
+    /// row list.
+    fn clear_search(&mut self) {
+        self.search_mode = false;
+        self.search_query.clear();
+        self.row_state.select(Some(0));
+    }
+
+    /// Polls every in-flight request without blocking, moving finished ones
+    /// into the cache. Called once per loop iteration, whichever event woke
+    /// the loop, so results surface on the very next redraw. A failed fetch
+    /// (or a worker that vanished without replying) just drops its pending
+    /// entry, so the board goes back to "Loading..." and is requested again
+    /// the next time it is selected, paged or refreshed instead of hanging
+    /// forever; nothing retries it automatically.
+    fn poll_pending(&mut self) {
+        let mut finished = vec![];
+        for (uri, receiver) in self.pending.iter() {
+            match receiver.try_recv() {
+                Ok(result) => finished.push((uri.clone(), result.ok())),
+                Err(mpsc::TryRecvError::Disconnected) => finished.push((uri.clone(), None)),
+                Err(mpsc::TryRecvError::Empty) => {}
+            }
+        }
+
+        for (uri, resp) in finished {
+            self.pending.remove(&uri);
+            if let Some(resp) = resp {
+                let doc = resp.text().unwrap_or_default().replace(&['\n', '\t'], "");
+                self.row_cache
+                    .insert(uri, board::BoardRow::get_board_data(doc));
+            }
+        }
+
+        if let Some(receiver) = &self.post_pending {
+            match receiver.try_recv() {
+                Ok(result) => {
+                    self.post_pending = None;
+                    if let Ok(resp) = result {
+                        let doc = resp.text().unwrap_or_default().replace(&['\n', '\t'], "");
+                        self.current_post = Some(board::Post::get_post_data(doc));
+                    }
+                }
+                Err(mpsc::TryRecvError::Disconnected) => self.post_pending = None,
+                Err(mpsc::TryRecvError::Empty) => {}
+            }
         }
     }
+
+    /// Fetches the article and comments for the selected row and switches
+    /// into the `Post` view.
+    fn open_selected_post(&mut self) {
+        let selected = self.row_state.selected().unwrap_or(0);
+        let url = match self.visible_rows().get(selected) {
+            Some(row) => row.url.clone(),
+            None => return,
+        };
+
+        let board = self.selected_board().clone();
+        self.current_post = None;
+        self.post_scroll = 0;
+        self.post_pending = Some(request_post(&board, &url));
+        self.active_menu_item = MenuItem::Post;
+    }
+
+    /// Leaves the `Post` view and returns to the board listing.
+    fn close_post(&mut self) {
+        self.active_menu_item = MenuItem::Boards;
+        self.current_post = None;
+        self.post_pending = None;
+        self.post_scroll = 0;
+    }
+
+    /// Resolves a left click at terminal coordinates `(column, row)` against
+    /// the board list / row table areas rendered on the last frame.
+    fn handle_click(&mut self, column: u16, row: u16) {
+        if !matches!(self.active_menu_item, MenuItem::Boards) {
+            return;
+        }
+
+        if area_contains(self.board_list_area, column, row) {
+            // Account for the surrounding block border.
+            let index = (row - self.board_list_area.y).saturating_sub(1) as usize;
+            if index < self.boards.len() {
+                self.select_board(index);
+            }
+        } else if area_contains(self.row_table_area, column, row) {
+            // Account for the border and header row. `TableState` doesn't
+            // expose its scroll offset, so this only resolves correctly
+            // while the table hasn't scrolled past its first viewport.
+            let index = (row - self.row_table_area.y).saturating_sub(2) as usize;
+            if index < self.visible_rows().len() {
+                self.row_state.select(Some(index));
+            }
+        }
+    }
+}
+
+fn area_contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x
+        && column < area.x + area.width
+        && row >= area.y
+        && row < area.y + area.height
+}
+
+/// Scores `candidate` against `query` as a case-insensitive subsequence
+/// match: every character of `query` must appear in `candidate`, in order.
+/// Earlier and more consecutive matches score higher. Returns `None` when
+/// `query` isn't a subsequence of `candidate`.
+fn fuzzy_score(candidate: &str, query: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let lowered_query = query.to_lowercase();
+    let mut query = lowered_query.chars();
+    let Some(mut target) = query.next() else {
+        return Some(0);
+    };
+
+    let mut score = 0;
+    let mut run = 0;
+    let mut matched_last = false;
+    for (position, &c) in candidate.iter().enumerate() {
+        if c != target {
+            matched_last = false;
+            run = 0;
+            continue;
+        }
+
+        run = if matched_last { run + 1 } else { 1 };
+        matched_last = true;
+        score += (candidate.len() as i32 - position as i32) + run * 5;
+
+        target = match query.next() {
+            Some(next) => next,
+            None => return Some(score),
+        };
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod fuzzy_score_tests {
+    use super::fuzzy_score;
+
+    #[test]
+    fn matches_case_insensitively() {
+        assert!(fuzzy_score("Rust Programming", "rust").is_some());
+    }
+
+    #[test]
+    fn rejects_out_of_order_query() {
+        assert_eq!(fuzzy_score("abc", "cab"), None);
+    }
+
+    #[test]
+    fn rejects_non_subsequence() {
+        assert_eq!(fuzzy_score("abc", "abd"), None);
+    }
+
+    #[test]
+    fn scores_consecutive_runs_higher_than_scattered_matches() {
+        let consecutive = fuzzy_score("abcdef", "abc").unwrap();
+        let scattered = fuzzy_score("axbxcx", "abc").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn scores_earlier_matches_higher_than_later_ones() {
+        let earlier = fuzzy_score("abcxxx", "a").unwrap();
+        let later = fuzzy_score("xxxabc", "a").unwrap();
+        assert!(earlier > later);
+    }
+
+    #[test]
+    fn empty_query_matches_everything_with_zero_score() {
+        assert_eq!(fuzzy_score("anything", ""), Some(0));
+    }
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Load the board list before touching the terminal at all, so an
+    // unreadable or malformed boards.json (or one that can't be created)
+    // exits with a plain error message instead of panicking with raw mode /
+    // mouse capture left enabled on the user's shell.
+    let mut app = match App::new() {
+        Ok(app) => app,
+        Err(err) => {
+            eprintln!("can't load board list: {}", err);
+            return Ok(());
+        }
+    };
+
     enable_raw_mode().expect("can run in raw mode");
+    execute!(io::stdout(), EnableMouseCapture).expect("can capture mouse events");
 
     let (tx, rx) = mpsc::channel();
-    let tick_rate = Duration::from_millis(10000);
+    let tick_rate = Duration::from_millis(200);
 
     thread::spawn(move || {
         let mut last_tic = Instant::now();
@@ -59,9 +412,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .unwrap_or_else(|| Duration::from_secs(0));
 
             if event::poll(timeout).expect("poll works") {
-                if let CEvent::Key(key) = event::read().expect("can read events") {
-                    tx.send(Event::Input(key)).expect("can send events");
-                }
+                let ev = event::read().expect("can read events");
+                tx.send(Event::Input(ev)).expect("can send events");
             }
 
             if last_tic.elapsed() >= tick_rate {
@@ -78,11 +430,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     terminal.clear()?;
 
     let menu_title = vec!["Home", "Boards", "Quit"];
-    let mut active_menu_item = MenuItem::Home;
-    let mut board_list_state = ListState::default();
-    board_list_state.select(Some(0));
 
     loop {
+        // Pick up anything the worker pool finished since the last
+        // iteration, regardless of which event woke us up, so results
+        // surface on the next input event or, at the latest, the next Tick.
+        app.poll_pending();
+
         terminal.draw(|rect| {
             let size = rect.size();
             let chunks = Layout::default()
@@ -126,57 +480,156 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .collect();
 
             let tabs = Tabs::new(menu)
-                .select(active_menu_item.into())
+                .select(app.active_menu_item.into())
                 .block(Block::default().title("Menu").borders(Borders::ALL))
                 .style(Style::default().fg(Color::White))
                 .highlight_style(Style::default().fg(Color::Yellow))
                 .divider(Span::raw("|"));
 
             rect.render_widget(tabs, chunks[0]);
-            match active_menu_item {
+            match app.active_menu_item {
                 MenuItem::Home => rect.render_widget(render_home(), chunks[1]),
                 MenuItem::Boards => {
+                    let show_search = app.search_mode || !app.search_query.is_empty();
+                    let vertical_constraints = if show_search {
+                        vec![Constraint::Length(1), Constraint::Min(1)]
+                    } else {
+                        vec![Constraint::Min(1)]
+                    };
+                    let boards_area = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints(vertical_constraints.as_slice())
+                        .split(chunks[1]);
+
+                    if show_search {
+                        let search_bar = Paragraph::new(format!("/{}", app.search_query))
+                            .style(Style::default().fg(Color::Yellow));
+                        rect.render_widget(search_bar, boards_area[0]);
+                    }
+
                     let boards_chunks = Layout::default()
                         .direction(Direction::Horizontal)
                         .constraints(
                             [Constraint::Percentage(20), Constraint::Percentage(80)].as_ref(),
                         )
+                        .split(boards_area[boards_area.len() - 1]);
+                    let (left, right) = render_boards(&app);
+                    rect.render_stateful_widget(left, boards_chunks[0], &mut app.board_list_state);
+                    rect.render_stateful_widget(right, boards_chunks[1], &mut app.row_state);
+                    app.board_list_area = boards_chunks[0];
+                    app.row_table_area = boards_chunks[1];
+                }
+                MenuItem::Post => {
+                    let post_chunks = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints(
+                            [Constraint::Percentage(60), Constraint::Percentage(40)].as_ref(),
+                        )
                         .split(chunks[1]);
-                    let (left, right) = render_boards(&board_list_state);
-                    rect.render_stateful_widget(left, boards_chunks[0], &mut board_list_state);
-                    rect.render_widget(right, boards_chunks[1]);
+                    let (body, comments) = render_post(&app);
+                    rect.render_widget(body, post_chunks[0]);
+                    rect.render_widget(comments, post_chunks[1]);
                 }
             }
             rect.render_widget(copyright, chunks[2]);
         })?;
 
         match rx.recv()? {
-            Event::Input(event) => match event.code {
+            Event::Input(CEvent::Mouse(mouse)) => {
+                if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
+                    app.handle_click(mouse.column, mouse.row);
+                }
+            }
+            // The loop always redraws on its next iteration, so there is
+            // nothing else to do here beyond letting `terminal.draw` pick up
+            // the new size.
+            Event::Input(CEvent::Resize(_, _)) => {}
+            Event::Input(CEvent::Key(key)) if app.search_mode => match key.code {
+                KeyCode::Esc => app.clear_search(),
+                KeyCode::Enter => app.search_mode = false,
+                KeyCode::Backspace => app.pop_search_char(),
+                KeyCode::Char(c) => app.push_search_char(c),
+                _ => {}
+            },
+            Event::Input(CEvent::Key(key)) => match key.code {
                 KeyCode::Char('q') => {
+                    execute!(io::stdout(), DisableMouseCapture)?;
                     disable_raw_mode()?;
                     terminal.show_cursor()?;
                     break;
                 }
-                KeyCode::Char('h') => active_menu_item = MenuItem::Home,
-                KeyCode::Char('b') => active_menu_item = MenuItem::Boards,
-                KeyCode::Down => {
-                    if let Some(selected) = board_list_state.selected() {
-                        let amount_boards = read_boards().unwrap().len();
-                        if selected >= amount_boards - 1 {
-                            board_list_state.select(Some(0));
+                KeyCode::Char('h') => app.active_menu_item = MenuItem::Home,
+                KeyCode::Char('b') => {
+                    if let MenuItem::Post = app.active_menu_item {
+                        app.close_post();
+                    }
+                    app.active_menu_item = MenuItem::Boards;
+                    app.ensure_board_rows_requested();
+                }
+                KeyCode::Char('r') => {
+                    if let MenuItem::Boards = app.active_menu_item {
+                        app.refresh_selected_board();
+                    }
+                }
+                KeyCode::Esc => {
+                    if let MenuItem::Post = app.active_menu_item {
+                        app.close_post();
+                    }
+                }
+                KeyCode::Char('/') => {
+                    if let MenuItem::Boards = app.active_menu_item {
+                        app.start_search();
+                    }
+                }
+                KeyCode::Enter => {
+                    if let MenuItem::Boards = app.active_menu_item {
+                        app.open_selected_post();
+                    }
+                }
+                KeyCode::Down => match app.active_menu_item {
+                    MenuItem::Boards => app.move_row_selection(1),
+                    MenuItem::Post => app.post_scroll = app.post_scroll.saturating_add(1),
+                    MenuItem::Home => {}
+                },
+                KeyCode::Up => match app.active_menu_item {
+                    MenuItem::Boards => app.move_row_selection(-1),
+                    MenuItem::Post => app.post_scroll = app.post_scroll.saturating_sub(1),
+                    MenuItem::Home => {}
+                },
+                KeyCode::Char('j') => {
+                    if let MenuItem::Boards = app.active_menu_item {
+                        let amount_boards = app.boards.len();
+                        let selected = app.board_list_state.selected().unwrap_or(0);
+                        let next = if selected + 1 >= amount_boards {
+                            0
                         } else {
-                            board_list_state.select(Some(selected + 1));
-                        }
+                            selected + 1
+                        };
+                        app.select_board(next);
                     }
                 }
-                KeyCode::Up => {
-                    if let Some(selected) = board_list_state.selected() {
-                        let amount_boards = read_boards().unwrap().len();
-                        if selected > 0 {
-                            board_list_state.select(Some(selected - 1));
+                KeyCode::Char('k') => {
+                    if let MenuItem::Boards = app.active_menu_item {
+                        let amount_boards = app.boards.len();
+                        let selected = app.board_list_state.selected().unwrap_or(0);
+                        let previous = if selected == 0 {
+                            amount_boards - 1
                         } else {
-                            board_list_state.select(Some(amount_boards - 1));
-                        }
+                            selected - 1
+                        };
+                        app.select_board(previous);
+                    }
+                }
+                KeyCode::PageDown | KeyCode::Char('n') => {
+                    if let MenuItem::Boards = app.active_menu_item {
+                        let page = app.page + 1;
+                        app.go_to_page(page);
+                    }
+                }
+                KeyCode::PageUp | KeyCode::Char('p') => {
+                    if let MenuItem::Boards = app.active_menu_item {
+                        let page = app.page.saturating_sub(1);
+                        app.go_to_page(page);
                     }
                 }
                 _ => {}
@@ -186,25 +639,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     Ok(())
-
-    // loop {
-    //     match read().unwrap() {
-    //         Event::Key(key_event) => {
-    //             let KeyEvent { code, modifiers } = key_event;
-    //             match (code, modifiers) {
-    //                 (KeyCode::Char(c), _) => {  },
-    //                 (KeyCode::Esc, _) => {
-    //
-    //                 }
-    //                 (_, _) => {}
-    //             }
-    //         }
-    //         Event::Mouse(_) => {}
-    //         Event::Resize(w, h) => {
-    //             println!("window resized to {w} x {h}");
-    //         }
-    //     }
-    // }
 }
 
 fn render_home<'a>() -> Paragraph<'a> {
@@ -232,15 +666,15 @@ fn render_home<'a>() -> Paragraph<'a> {
     home
 }
 
-fn render_boards<'a>(board_list_state: &ListState) -> (List<'a>, Table<'a>) {
+fn render_boards<'a>(app: &App) -> (List<'a>, Table<'a>) {
     let board = Block::default()
         .borders(Borders::ALL)
         .style(Style::default().fg(Color::White))
         .title("Boards")
         .border_type(BorderType::Plain);
 
-    let boards = read_boards().expect("can fetch board list");
-    let items: Vec<_> = boards
+    let items: Vec<_> = app
+        .boards
         .iter()
         .map(|board| {
             ListItem::new(Spans::from(vec![Span::styled(
@@ -250,15 +684,6 @@ fn render_boards<'a>(board_list_state: &ListState) -> (List<'a>, Table<'a>) {
         })
         .collect();
 
-    let selected_board = boards
-        .get(
-            board_list_state
-                .selected()
-                .expect("there is always a selected board"),
-        )
-        .expect("exists")
-        .clone();
-
     let list = List::new(items).block(board).highlight_style(
         Style::default()
             .bg(Color::Yellow)
@@ -266,18 +691,21 @@ fn render_boards<'a>(board_list_state: &ListState) -> (List<'a>, Table<'a>) {
             .add_modifier(Modifier::BOLD),
     );
 
-    let board_rows = read_board_rows(selected_board.uri.as_str());
     let mut cells = vec![];
-    for board_row in board_rows.unwrap() {
-        let row = Row::new(vec![
-            Cell::from(Span::raw(board_row.title.to_string())),
-            Cell::from(Span::raw(board_row.comment_count.to_string())),
-            Cell::from(Span::raw(board_row.nickname.to_string())),
-            Cell::from(Span::raw(board_row.hit_count.to_string())),
-            Cell::from(Span::raw(board_row.timestamp.to_string())),
-        ]);
-        cells.push(row);
+    if app.row_cache.contains_key(&app.current_cache_key()) {
+        for board_row in app.visible_rows() {
+            cells.push(Row::new(vec![
+                Cell::from(Span::raw(board_row.title.to_string())),
+                Cell::from(Span::raw(board_row.comment_count.to_string())),
+                Cell::from(Span::raw(board_row.nickname.to_string())),
+                Cell::from(Span::raw(board_row.hit_count.to_string())),
+                Cell::from(Span::raw(board_row.timestamp.to_string())),
+            ]));
+        }
+    } else {
+        cells.push(Row::new(vec![Cell::from(Span::raw("Loading..."))]));
     }
+
     let board_row = Table::new(cells)
         .header(Row::new(vec![
             Cell::from(Span::styled(
@@ -305,9 +733,15 @@ fn render_boards<'a>(board_list_state: &ListState) -> (List<'a>, Table<'a>) {
             Block::default()
                 .borders(Borders::ALL)
                 .style(Style::default().fg(Color::White))
-                .title("목록")
+                .title(format!("목록 (p.{})", app.page + 1))
                 .border_type(BorderType::Plain),
         )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Yellow)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        )
         .widths(&[
             Constraint::Percentage(50),
             Constraint::Percentage(8),
@@ -319,8 +753,66 @@ fn render_boards<'a>(board_list_state: &ListState) -> (List<'a>, Table<'a>) {
     (list, board_row)
 }
 
-fn read_boards() -> Result<Vec<board::Board>, Error> {
-    let board_list = vec![
+fn render_post<'a>(app: &App) -> (Paragraph<'a>, List<'a>) {
+    let body = match &app.current_post {
+        Some(post) => Paragraph::new(vec![
+            Spans::from(vec![Span::styled(
+                post.title.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Spans::from(vec![Span::styled(
+                format!("by {}", post.author),
+                Style::default().fg(Color::DarkGray),
+            )]),
+            Spans::from(vec![Span::raw("")]),
+            Spans::from(vec![Span::raw(post.body.clone())]),
+        ]),
+        None => Paragraph::new("Loading..."),
+    }
+    .wrap(Wrap { trim: false })
+    .scroll((app.post_scroll, 0))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::White))
+            .title("본문")
+            .border_type(BorderType::Plain),
+    );
+
+    let comment_items: Vec<ListItem> = app
+        .current_post
+        .iter()
+        .flat_map(|post| &post.comments)
+        .map(|comment| {
+            ListItem::new(vec![
+                Spans::from(vec![
+                    Span::styled(comment.nickname.clone(), Style::default().fg(Color::Yellow)),
+                    Span::raw("  "),
+                    Span::styled(
+                        comment.timestamp.clone(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]),
+                Spans::from(vec![Span::raw(comment.text.clone())]),
+            ])
+        })
+        .collect();
+
+    let comments = List::new(comment_items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::White))
+            .title("댓글")
+            .border_type(BorderType::Plain),
+    );
+
+    (body, comments)
+}
+
+const DEFAULT_HOST: &str = "https://www.clien.net";
+
+fn default_boards() -> Vec<board::Board> {
+    vec![
         board::Board::new("모두의공원", "board/park"),
         board::Board::new("새로운소식", "board/news"),
         board::Board::new("유용한사이트", "board/useful"),
@@ -328,17 +820,61 @@ fn read_boards() -> Result<Vec<board::Board>, Error> {
         board::Board::new("팁과강좌", "board/lecture"),
         board::Board::new("사용기", "board/use"),
         board::Board::new("추천글", "recommend"),
-    ];
+    ]
+}
 
-    Ok(board_list)
+/// Standard per-user config location for the board list (`$XDG_CONFIG_HOME`
+/// or `$HOME/.config`, falling back to the current directory).
+fn config_path() -> PathBuf {
+    let config_home = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|_| PathBuf::from("."));
+
+    config_home.join("commweb-tui").join("boards.json")
 }
 
-fn read_board_rows(board_code: &str) -> Result<Vec<board::BoardRow>, Error> {
-    let mut url = "https://www.clien.net/service/".to_owned();
-    url.push_str(board_code);
-    let resp = network::request_url(Method::GET, url);
-    let doc = resp.text().unwrap().replace(&['\n', '\t'], "");
+/// Loads the board list from the user's config file, creating it with the
+/// current defaults on first run.
+fn read_boards() -> Result<Vec<board::Board>, Error> {
+    let path = config_path();
+    if !path.exists() {
+        let boards = default_boards();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(&boards)?)?;
+        return Ok(boards);
+    }
+
+    let raw = fs::read_to_string(&path)?;
+    let boards: Vec<board::Board> = serde_json::from_str(&raw)?;
+    if boards.is_empty() {
+        return Ok(default_boards());
+    }
+    Ok(boards)
+}
+
+/// Cache/in-flight key for a board's rows at a given page.
+fn cache_key(uri: &str, page: usize) -> String {
+    format!("{}?po={}", uri, page)
+}
+
+/// Enqueues a background fetch for `board`'s `page` on the network worker
+/// pool.
+fn request_board_rows(board: &board::Board, page: usize) -> Receiver<reqwest::Result<Response>> {
+    let host = board.base_host.as_deref().unwrap_or(DEFAULT_HOST);
+    let url = format!("{}/service/{}?&po={}", host, board.uri, page);
+    network::execute(Method::GET, url)
+}
 
-    let board_row = board::BoardRow::get_board_data(doc);
-    Ok(board_row)
+/// Enqueues a background fetch for an article page on `board`'s host.
+fn request_post(board: &board::Board, url: &str) -> Receiver<reqwest::Result<Response>> {
+    let host = board.base_host.as_deref().unwrap_or(DEFAULT_HOST);
+    let full_url = if url.starts_with("http") {
+        url.to_string()
+    } else {
+        format!("{}{}", host, url)
+    };
+    network::execute(Method::GET, full_url)
 }
@@ -1,14 +1,68 @@
 use reqwest::blocking::{Client, Response};
 use reqwest::Method;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
 
-pub fn request_url(http_method: Method, url: String) -> Response {
-    let client = Client::new();
+/// Number of blocking worker threads kept around to service `execute` calls.
+const POOL_SIZE: usize = 4;
 
-    let resp = client
-        .request(http_method, url)
-        .send()
-        .expect("Unable to get response.")
-        ;
+struct Job {
+    method: Method,
+    url: String,
+    reply: Sender<reqwest::Result<Response>>,
+}
 
-    resp
-}
\ No newline at end of file
+struct WorkerPool {
+    jobs: Sender<Job>,
+}
+
+impl WorkerPool {
+    fn new(size: usize) -> Self {
+        let (jobs, job_rx) = mpsc::channel::<Job>();
+        let job_rx = Arc::new(Mutex::new(job_rx));
+
+        for _ in 0..size {
+            let job_rx = Arc::clone(&job_rx);
+            thread::spawn(move || {
+                let client = Client::new();
+                loop {
+                    let job = {
+                        let job_rx = job_rx.lock().expect("worker pool mutex poisoned");
+                        job_rx.recv()
+                    };
+                    let job = match job {
+                        Ok(job) => job,
+                        Err(_) => break,
+                    };
+
+                    let result = client.request(job.method, job.url).send();
+                    let _ = job.reply.send(result);
+                }
+            });
+        }
+
+        Self { jobs }
+    }
+}
+
+fn pool() -> &'static WorkerPool {
+    static POOL: OnceLock<WorkerPool> = OnceLock::new();
+    POOL.get_or_init(|| WorkerPool::new(POOL_SIZE))
+}
+
+/// Enqueues a request on the background worker pool and returns immediately.
+///
+/// The caller polls the returned receiver (e.g. with `try_recv` on every
+/// loop iteration) instead of blocking, so callers on the UI thread never
+/// stall on network I/O. The receiver yields the send error too, so a
+/// failed fetch can be noticed and retried instead of leaving the caller
+/// waiting forever.
+pub fn execute(method: Method, url: String) -> Receiver<reqwest::Result<Response>> {
+    let (reply, response) = mpsc::channel();
+    pool()
+        .jobs
+        .send(Job { method, url, reply })
+        .expect("worker pool thread panicked");
+    response
+}